@@ -21,6 +21,27 @@
 //! and forwards all the formatting traits (except [`Debug`] and
 //! [`Pointer`](std::fmt::Pointer)) to its contained-type.
 //!
+//! It also offers the same adapter surface as [`Option`] (`map`, `and_then`, `filter`,
+//! `inspect`, `unwrap_or`, `unwrap_or_else` and `unwrap_or_default`) so values can be
+//! transformed without manually matching on emptiness.
+//!
+//! It implements [`IntoIterator`] (for [`struct@OnceOption<T>`], `&OnceOption<T>` and
+//! `&mut OnceOption<T>`), yielding zero or one element, so it composes with iterator
+//! pipelines the same way [`Option`] does.
+//!
+//! It also offers non-panicking accessors (`get`, `get_mut`, `as_option`, `as_deref` and
+//! `take_if`) for code, such as a [`Drop`] implementation, that cannot risk a second `take`.
+//!
+//! It can be converted to and from a plain [`Option`] (`into_option`, [`From`]) and into a
+//! [`Result`] (`ok_or`, `ok_or_else`), to bridge into `?`-based error propagation.
+//!
+//! With the `serde` feature enabled, [`struct@OnceOption<T>`] implements `Serialize` and
+//! `Deserialize` whenever `T` does, serializing exactly like [`Option<T>`] (the inner value,
+//! or `null`/unit when empty).
+//!
+//! It mirrors [`Option::zip`] and [`Option::unzip`] (`zip`, `unzip`), to combine or split
+//! two single-shot values as a unit.
+//!
 //! # Rationale
 //!
 //! The main, but not only, purpose of [`struct@OnceOption`] is to simplify
@@ -353,6 +374,364 @@ impl<T> OnceOption<T> {
             core::any::type_name::<T>()
         )
     }
+
+    /// Maps a [`struct@OnceOption<T>`] to [`struct@OnceOption<U>`] by applying a function to
+    /// the contained value (if any), consuming the `self` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let x = OnceOption("value");
+    /// assert_eq!(x.map(|s| s.len()).unwrap(), 5);
+    ///
+    /// let x: OnceOption<&str> = OnceOption::NONE;
+    /// assert!(x.map(|s| s.len()).is_none());
+    /// ```
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> OnceOption<U> {
+        match self.inner {
+            Some(value) => OnceOption(f(value)),
+            None => OnceOption::NONE,
+        }
+    }
+
+    /// Calls `f` with the contained value (if any) and returns the result, consuming the
+    /// `self` value. This is also known as "flat map" in other languages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// fn half(x: u32) -> OnceOption<u32> {
+    ///     if x % 2 == 0 {
+    ///         OnceOption(x / 2)
+    ///     } else {
+    ///         OnceOption::NONE
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(OnceOption(4).and_then(half).unwrap(), 2);
+    /// assert!(OnceOption(3).and_then(half).is_none());
+    /// assert!(OnceOption::<u32>::NONE.and_then(half).is_none());
+    /// ```
+    #[inline]
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> OnceOption<U>) -> OnceOption<U> {
+        match self.inner {
+            Some(value) => f(value),
+            None => OnceOption::NONE,
+        }
+    }
+
+    /// Returns [`OnceOption::NONE`] if the once-option is empty, otherwise calls `predicate`
+    /// with the contained value and returns:
+    ///
+    /// - a [`struct@OnceOption`] containing the value, if `predicate` returns `true`;
+    /// - [`OnceOption::NONE`], if `predicate` returns `false`.
+    ///
+    /// Since this consumes `self`, a once-option that gets filtered out simply stays empty:
+    /// there is no path that re-populates an already-taken once-option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// assert!(OnceOption(4).filter(|x| x % 2 == 0).is_some());
+    /// assert!(OnceOption(3).filter(|x| x % 2 == 0).is_none());
+    /// assert!(OnceOption::<u32>::NONE.filter(|x| x % 2 == 0).is_none());
+    /// ```
+    #[inline]
+    pub fn filter(self, predicate: impl FnOnce(&T) -> bool) -> OnceOption<T> {
+        match self.inner {
+            Some(value) if predicate(&value) => OnceOption(value),
+            _ => OnceOption::NONE,
+        }
+    }
+
+    /// Calls `f` with a reference to the contained value (if any), then returns `self`
+    /// unchanged, for chaining with other adapters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let x = OnceOption(4).inspect(|v| assert_eq!(*v, 4));
+    /// assert_eq!(x.unwrap(), 4);
+    /// ```
+    #[inline]
+    pub fn inspect(self, f: impl FnOnce(&T)) -> Self {
+        if let Some(value) = &self.inner {
+            f(value);
+        }
+        self
+    }
+
+    /// Returns the contained value, or `default` if the once-option is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// assert_eq!(OnceOption(4).unwrap_or(0), 4);
+    /// assert_eq!(OnceOption::<u32>::NONE.unwrap_or(0), 0);
+    /// ```
+    #[inline]
+    pub fn unwrap_or(self, default: T) -> T {
+        self.inner.unwrap_or(default)
+    }
+
+    /// Returns the contained value, or computes it from `f` if the once-option is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// assert_eq!(OnceOption(4).unwrap_or_else(|| 0), 4);
+    /// assert_eq!(OnceOption::<u32>::NONE.unwrap_or_else(|| 7), 7);
+    /// ```
+    #[inline]
+    pub fn unwrap_or_else(self, f: impl FnOnce() -> T) -> T {
+        self.inner.unwrap_or_else(f)
+    }
+}
+
+impl<T: Default> OnceOption<T> {
+    /// Returns the contained value, or the default value of `T` if the once-option is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// assert_eq!(OnceOption(4).unwrap_or_default(), 4);
+    /// assert_eq!(OnceOption::<u32>::NONE.unwrap_or_default(), 0);
+    /// ```
+    #[inline]
+    pub fn unwrap_or_default(self) -> T {
+        self.inner.unwrap_or_default()
+    }
+}
+
+impl<T> OnceOption<T> {
+    /// Returns a borrow of the contained value, or `None` if the once-option is empty.
+    ///
+    /// Unlike [`Deref`](core::ops::Deref), this never panics, which makes it safe to call
+    /// even after the once-option may have already been [`take`](Self::take)n, for example
+    /// from a [`Drop`] implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let mut x = OnceOption(4);
+    /// assert_eq!(x.get(), Some(&4));
+    /// x.take();
+    /// assert_eq!(x.get(), None);
+    /// ```
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        self.inner.as_ref()
+    }
+
+    /// Returns a mutable borrow of the contained value, or `None` if the once-option is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let mut x = OnceOption(4);
+    /// if let Some(v) = x.get_mut() {
+    ///     *v = 5;
+    /// }
+    /// assert_eq!(x.unwrap(), 5);
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.inner.as_mut()
+    }
+
+    /// Borrows the once-option's contents as a plain [`Option<&T>`], for interop with code
+    /// that expects the standard [`Option`] API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let x = OnceOption(4);
+    /// assert_eq!(x.as_option(), Some(&4));
+    /// ```
+    #[inline]
+    pub fn as_option(&self) -> Option<&T> {
+        self.inner.as_ref()
+    }
+
+    /// Removes and returns the contained value if it is present and `predicate` returns
+    /// `true` when called with a mutable borrow of it; otherwise returns `None` and leaves
+    /// the once-option untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let mut x = OnceOption(4);
+    /// assert_eq!(x.take_if(|v| *v % 2 == 1), None);
+    /// assert_eq!(x.get(), Some(&4));
+    ///
+    /// assert_eq!(x.take_if(|v| *v % 2 == 0), Some(4));
+    /// assert_eq!(x.get(), None);
+    /// ```
+    #[inline]
+    pub fn take_if(&mut self, predicate: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        if self.inner.as_mut().is_some_and(predicate) {
+            self.inner.take()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: core::ops::Deref> OnceOption<T> {
+    /// Borrows the contained value and dereferences it, or returns `None` if the once-option
+    /// is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let x: OnceOption<String> = OnceOption("value".to_string());
+    /// assert_eq!(x.as_deref(), Some("value"));
+    /// ```
+    #[inline]
+    pub fn as_deref(&self) -> Option<&T::Target> {
+        self.inner.as_deref()
+    }
+}
+
+impl<T> OnceOption<T> {
+    /// Converts the once-option into a [`Result`], mapping the contained value to `Ok` and
+    /// an empty once-option to `Err(err)`, consuming the `self` value.
+    ///
+    /// This lets a [`struct@OnceOption`] field participate in `?`-based error propagation by
+    /// first converting it into a [`Result`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// assert_eq!(OnceOption(4).ok_or("empty"), Ok(4));
+    /// assert_eq!(OnceOption::<u32>::NONE.ok_or("empty"), Err("empty"));
+    /// ```
+    #[inline]
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        self.inner.ok_or(err)
+    }
+
+    /// Converts the once-option into a [`Result`], mapping the contained value to `Ok` and
+    /// an empty once-option to `Err(f())`, consuming the `self` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// assert_eq!(OnceOption(4).ok_or_else(|| "empty"), Ok(4));
+    /// assert_eq!(OnceOption::<u32>::NONE.ok_or_else(|| "empty"), Err("empty"));
+    /// ```
+    #[inline]
+    pub fn ok_or_else<E>(self, f: impl FnOnce() -> E) -> Result<T, E> {
+        self.inner.ok_or_else(f)
+    }
+
+    /// Converts the once-option into a plain [`Option<T>`], consuming the `self` value.
+    ///
+    /// This unwinds a [`struct@OnceOption`] back into an ordinary [`Option`], for interop
+    /// with the broader [`Option`] ecosystem. [`From`] is also implemented for this
+    /// conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// assert_eq!(OnceOption(4).into_option(), Some(4));
+    /// assert_eq!(OnceOption::<u32>::NONE.into_option(), None);
+    /// ```
+    #[inline]
+    pub fn into_option(self) -> Option<T> {
+        self.inner
+    }
+}
+
+impl<T> From<OnceOption<T>> for Option<T> {
+    /// Converts a [`struct@OnceOption`] into a plain [`Option<T>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let o: Option<u8> = OnceOption(67).into();
+    ///
+    /// assert_eq!(o, Some(67));
+    /// ```
+    #[inline]
+    fn from(val: OnceOption<T>) -> Option<T> {
+        val.inner
+    }
+}
+
+impl<T> OnceOption<T> {
+    /// Combines `self` and `other` into a [`struct@OnceOption`] of a pair, consuming both
+    /// values. The result is empty if either `self` or `other` is empty.
+    ///
+    /// Because [`struct@OnceOption`] is move-consuming, this cleanly combines two
+    /// single-shot resources (for example two [`JoinHandle`](std::thread::JoinHandle)s that
+    /// must both be joined at drop) into a single unit; [`unzip`](Self::unzip) splits them
+    /// back out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let x = OnceOption(1);
+    /// let y = OnceOption("hi");
+    /// assert_eq!(x.zip(y).unwrap(), (1, "hi"));
+    ///
+    /// let x = OnceOption(1);
+    /// let y: OnceOption<&str> = OnceOption::NONE;
+    /// assert!(x.zip(y).is_none());
+    /// ```
+    #[inline]
+    pub fn zip<U>(self, other: OnceOption<U>) -> OnceOption<(T, U)> {
+        match (self.inner, other.inner) {
+            (Some(a), Some(b)) => OnceOption((a, b)),
+            _ => OnceOption::NONE,
+        }
+    }
+}
+
+impl<A, B> OnceOption<(A, B)> {
+    /// Splits a [`struct@OnceOption`] of a pair into a pair of once-options, consuming
+    /// `self`. Both halves are empty if `self` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let x = OnceOption((1, "hi"));
+    /// let (a, b) = x.unzip();
+    /// assert_eq!(a.unwrap(), 1);
+    /// assert_eq!(b.unwrap(), "hi");
+    ///
+    /// let x: OnceOption<(u32, &str)> = OnceOption::NONE;
+    /// let (a, b) = x.unzip();
+    /// assert!(a.is_none());
+    /// assert!(b.is_none());
+    /// ```
+    #[inline]
+    pub fn unzip(self) -> (OnceOption<A>, OnceOption<B>) {
+        match self.inner {
+            Some((a, b)) => (OnceOption(a), OnceOption(b)),
+            None => (OnceOption::NONE, OnceOption::NONE),
+        }
+    }
 }
 
 impl<T> Default for OnceOption<T> {
@@ -465,3 +844,188 @@ impl<T: core::fmt::Debug> core::fmt::Debug for OnceOption<T> {
         }
     }
 }
+
+/// A single-shot iterator over either zero or one value, obtained by consuming a
+/// [`struct@OnceOption`] with [`IntoIterator::into_iter`].
+///
+/// See its documentation for more.
+pub struct IntoIter<T> {
+    inner: core::option::IntoIter<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T> core::iter::FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for OnceOption<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the once-option's value, yielding zero or one
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let x = OnceOption(4);
+    /// let v: Vec<_> = x.into_iter().collect();
+    /// assert_eq!(v, [4]);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            inner: self.inner.into_iter(),
+        }
+    }
+}
+
+/// An iterator over a reference to the value in a [`struct@OnceOption`], yielding zero or
+/// one element.
+///
+/// This struct is created by [`OnceOption::iter`]. See its documentation for more.
+pub struct Iter<'a, T> {
+    inner: core::option::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> core::iter::FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a OnceOption<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// An iterator over a mutable reference to the value in a [`struct@OnceOption`], yielding
+/// zero or one element.
+///
+/// This struct is created by [`OnceOption::iter_mut`]. See its documentation for more.
+pub struct IterMut<'a, T> {
+    inner: core::option::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> core::iter::FusedIterator for IterMut<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a mut OnceOption<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> OnceOption<T> {
+    /// Returns an iterator over the once-option's value, yielding zero or one element.
+    ///
+    /// An empty [`struct@OnceOption`] yields an empty sequence instead of panicking, which
+    /// makes this a safe way to visit the value and lets [`struct@OnceOption`] drop into
+    /// iterator pipelines (`.flatten()`, `.chain()`, `.extend()`, `for` loops, ...) the same
+    /// way [`Option`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let x = OnceOption(4);
+    /// assert_eq!(x.iter().next(), Some(&4));
+    ///
+    /// let x: OnceOption<u32> = OnceOption::NONE;
+    /// assert_eq!(x.iter().next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.inner.iter(),
+        }
+    }
+
+    /// Returns a mutable iterator over the once-option's value, yielding zero or one element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use once_option::OnceOption;
+    /// let mut x = OnceOption(4);
+    /// if let Some(v) = x.iter_mut().next() {
+    ///     *v = 5;
+    /// }
+    /// assert_eq!(x.unwrap(), 5);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.inner.iter_mut(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::OnceOption;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes exactly like [`Option<T>`](Option): the inner value, or `null`/unit when
+    /// empty.
+    impl<T: Serialize> Serialize for OnceOption<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.inner.serialize(serializer)
+        }
+    }
+
+    /// Deserializes exactly like [`Option<T>`](Option): a null/absent value produces the
+    /// empty state.
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for OnceOption<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Option::deserialize(deserializer).map(|inner| Self { inner })
+        }
+    }
+}
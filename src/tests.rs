@@ -118,6 +118,165 @@ fn impl_display_float_formats() {
     assert_eq!(format!("{:+0.02}", f), "+3.14");
 }
 
+#[test]
+fn combinator_map_and_then() {
+    let x = OnceOption(4);
+    assert_eq!(x.map(|v| v * 2).unwrap(), 8);
+
+    let x: OnceOption<u32> = OnceOption::NONE;
+    assert!(x.map(|v| v * 2).is_none());
+
+    fn half(x: u32) -> OnceOption<u32> {
+        if x % 2 == 0 {
+            OnceOption(x / 2)
+        } else {
+            OnceOption::NONE
+        }
+    }
+
+    assert_eq!(OnceOption(4).and_then(half).unwrap(), 2);
+    assert!(OnceOption(3).and_then(half).is_none());
+}
+
+#[test]
+fn combinator_filter() {
+    assert!(OnceOption(4).filter(|v| v % 2 == 0).is_some());
+    assert!(OnceOption(3).filter(|v| v % 2 == 0).is_none());
+    assert!(OnceOption::<u32>::NONE.filter(|v| v % 2 == 0).is_none());
+}
+
+#[test]
+fn combinator_inspect() {
+    let mut seen = 0;
+    let x = OnceOption(4).inspect(|v| seen = *v);
+    assert_eq!(seen, 4);
+    assert_eq!(x.unwrap(), 4);
+}
+
+#[test]
+fn combinator_unwrap_or() {
+    assert_eq!(OnceOption(4).unwrap_or(0), 4);
+    assert_eq!(OnceOption::<u32>::NONE.unwrap_or(0), 0);
+
+    assert_eq!(OnceOption(4).unwrap_or_else(|| 0), 4);
+    assert_eq!(OnceOption::<u32>::NONE.unwrap_or_else(|| 7), 7);
+
+    assert_eq!(OnceOption(4).unwrap_or_default(), 4);
+    assert_eq!(OnceOption::<u32>::NONE.unwrap_or_default(), 0);
+}
+
+#[test]
+fn impl_into_iterator() {
+    let x = OnceOption(4);
+    assert_eq!(x.into_iter().collect::<std::vec::Vec<_>>(), vec![4]);
+
+    let x: OnceOption<u32> = OnceOption::NONE;
+    assert!(x.into_iter().collect::<std::vec::Vec<u32>>().is_empty());
+}
+
+#[test]
+fn impl_iter_and_iter_mut() {
+    let x = OnceOption(4);
+    assert_eq!(x.iter().next(), Some(&4));
+    assert_eq!((&x).into_iter().next(), Some(&4));
+
+    let mut x = OnceOption(4);
+    if let Some(v) = x.iter_mut().next() {
+        *v = 5;
+    }
+    assert_eq!(x.unwrap(), 5);
+
+    let x: OnceOption<u32> = OnceOption::NONE;
+    assert_eq!(x.iter().next(), None);
+}
+
+#[test]
+fn accessor_get_and_get_mut() {
+    let mut x = OnceOption(4);
+    assert_eq!(x.get(), Some(&4));
+    assert_eq!(x.as_option(), Some(&4));
+
+    if let Some(v) = x.get_mut() {
+        *v = 5;
+    }
+    assert_eq!(x.get(), Some(&5));
+
+    x.take();
+    assert_eq!(x.get(), None);
+    assert_eq!(x.get_mut(), None);
+    assert_eq!(x.as_option(), None);
+}
+
+#[test]
+fn accessor_as_deref() {
+    let x: OnceOption<String> = OnceOption("value".to_string());
+    assert_eq!(x.as_deref(), Some("value"));
+
+    let x: OnceOption<String> = OnceOption::NONE;
+    assert_eq!(x.as_deref(), None);
+}
+
+#[test]
+fn accessor_take_if() {
+    let mut x = OnceOption(4);
+    assert_eq!(x.take_if(|v| *v % 2 == 1), None);
+    assert_eq!(x.get(), Some(&4));
+
+    assert_eq!(x.take_if(|v| *v % 2 == 0), Some(4));
+    assert_eq!(x.get(), None);
+    assert_eq!(x.take_if(|_| true), None);
+}
+
+#[test]
+fn result_conversions() {
+    assert_eq!(OnceOption(4).ok_or("empty"), Ok(4));
+    assert_eq!(OnceOption::<u32>::NONE.ok_or("empty"), Err("empty"));
+
+    assert_eq!(OnceOption(4).ok_or_else(|| "empty"), Ok(4));
+    assert_eq!(OnceOption::<u32>::NONE.ok_or_else(|| "empty"), Err("empty"));
+
+    assert_eq!(OnceOption(4).into_option(), Some(4));
+    assert_eq!(OnceOption::<u32>::NONE.into_option(), None);
+
+    let o: Option<u8> = OnceOption(67).into();
+    assert_eq!(o, Some(67));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let some: OnceOption<u32> = OnceOption(4);
+    assert_eq!(serde_json::to_string(&some).unwrap(), "4");
+    assert_eq!(serde_json::from_str::<OnceOption<u32>>("4").unwrap(), some);
+
+    let none: OnceOption<u32> = OnceOption::NONE;
+    assert_eq!(serde_json::to_string(&none).unwrap(), "null");
+    assert!(serde_json::from_str::<OnceOption<u32>>("null")
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn combinator_zip_unzip() {
+    let x = OnceOption(1);
+    let y = OnceOption("hi");
+    assert_eq!(x.zip(y).unwrap(), (1, "hi"));
+
+    let x = OnceOption(1);
+    let y: OnceOption<&str> = OnceOption::NONE;
+    assert!(x.zip(y).is_none());
+
+    let x = OnceOption((1, "hi"));
+    let (a, b) = x.unzip();
+    assert_eq!(a.unwrap(), 1);
+    assert_eq!(b.unwrap(), "hi");
+
+    let x: OnceOption<(u32, &str)> = OnceOption::NONE;
+    let (a, b) = x.unzip();
+    assert!(a.is_none());
+    assert!(b.is_none());
+}
+
 #[test]
 fn impl_debug() {
     let v = vec![1, 2, 3];